@@ -4,18 +4,21 @@
 //!
 //! The CO-OPS API is a single endpoint with multiple products that can be requested with different combinations of
 //! query parameters. This library was built to provide a type-safe interface for building requests and deserializing responses into
-//! dedicated structs. This library currently supports the "predictions" product, which includes predicted tide heights for
-//! specified stations and date ranges.
+//! dedicated structs. This library currently supports the `predictions`, `water_level`, `currents`, `air_temperature`,
+//! `water_temperature`, and `wind` products.
 //!
 //! Contributions to support additional products are welcome!
 //!
+//! Responses are JSON by default; [`NoaaTideClient::fetch_predictions_csv`] fetches predictions
+//! as CSV instead, which is considerably more compact for requests spanning many years.
+//!
 //! No API keys are required since the NOAA CO-OPS API does not require authentication, please be mindful with usage.
 //!
 //! # Example
 //!
 //! Below is an example to fetch high/low tide predictions for the San Francisco Golden Gate station for January 2026
 //! ```no_run
-//! use noaa_tides::{DateRange, Datum, Interval, NoaaTideClient, PredictionsRequest, Timezone, Units};
+//! use noaa_tides::{NoaaTideClient, PredictionsRequest};
 //!
 //! use chrono::NaiveDate;
 //!
@@ -23,17 +26,11 @@
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //!     let client = NoaaTideClient::new();
 //!
-//!     let request = PredictionsRequest {
-//!         station: "9414290".into(),
-//!         date_range: DateRange {
-//!             begin_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
-//!             end_date: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
-//!         },
-//!         datum: Datum::MLLW,
-//!         time_zone: Timezone::LST_LDT,
-//!         interval: Interval::HighLow,
-//!         units: Units::English,
-//!     };
+//!     let request = PredictionsRequest::builder()
+//!         .station("9414290")
+//!         .begin_date(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+//!         .end_date(NaiveDate::from_ymd_opt(2026, 1, 31).unwrap())
+//!         .build()?;
 //!
 //!     let data = client.fetch(&request).await?;
 //!     println!("High/low tide predictions:");
@@ -49,23 +46,49 @@
 //! }
 
 //!
+mod metadata;
 mod parameters;
 mod products;
 
-pub use crate::parameters::{DateRange, Datum, Interval, Timezone, Units};
-pub use crate::products::predictions::{PredictionsRequest, TideType};
+pub use crate::metadata::{Point, Station};
+pub use crate::parameters::{DateRange, Datum, Format, Interval, TimeRange, Timezone, Units};
+pub use crate::products::air_temperature::{
+    AirTemperature, AirTemperatureRequest, AirTemperatureResponse,
+};
+pub use crate::products::currents::{Current, CurrentsRequest, CurrentsResponse};
+pub use crate::products::predictions::{
+    PredictionsRequest, PredictionsResponse, TideType, ZonedPrediction,
+};
+pub use crate::products::water_level::{
+    WaterLevel, WaterLevelQuality, WaterLevelRequest, WaterLevelResponse,
+};
+pub use crate::products::water_temperature::{
+    WaterTemperature, WaterTemperatureRequest, WaterTemperatureResponse,
+};
+pub use crate::products::wind::{Wind, WindRequest, WindResponse};
+pub use crate::products::NoaaTideProduct;
 
-use crate::products::NoaaTideProduct;
+use crate::products::predictions::{max_span_days, split_date_range};
+use futures::stream::{self, StreamExt};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
 use thiserror::Error;
+use tokio::sync::Semaphore;
 
 const BASE_URL: &str = "https://api.tidesandcurrents.noaa.gov/api/prod/datagetter";
+const MDAPI_STATIONS_URL: &str =
+    "https://api.tidesandcurrents.noaa.gov/mdapi/prod/webapi/stations.json";
+
+/// Maximum number of chunked requests issued concurrently by [`NoaaTideClient::fetch_predictions_all`]
+const MAX_CONCURRENT_CHUNK_REQUESTS: usize = 4;
 
 /// Client to get data from the NOAA Tides and Currents API
 pub struct NoaaTideClient {
     http: Client,
     base_url: String,
+    mdapi_stations_url: String,
 }
 
 impl NoaaTideClient {
@@ -73,6 +96,7 @@ impl NoaaTideClient {
         Self {
             http: Client::new(),
             base_url: BASE_URL.to_string(),
+            mdapi_stations_url: MDAPI_STATIONS_URL.to_string(),
         }
     }
 
@@ -86,7 +110,10 @@ impl NoaaTideClient {
             .http
             .get(&self.base_url)
             .query(&params)
-            .query(&[("product", params.product_name()), ("format", "json")])
+            .query(&[
+                ("product", params.product_name()),
+                ("format", Format::Json.query_value()),
+            ])
             .send()
             .await?
             .json::<NoaaResponse<P::Response>>()
@@ -96,6 +123,126 @@ impl NoaaTideClient {
             NoaaResponse::Failure(wrapper) => Err(NoaaTideError::ApiError(wrapper.error.message)),
         }
     }
+
+    /// Fetch predictions with the response decoded as CSV rather than JSON.
+    ///
+    /// CSV responses are far more compact than JSON for large date ranges, which matters for
+    /// requests spanning many years, at the cost of losing any fields not modeled by
+    /// `Prediction`. Rows are parsed into the same `Prediction` type regardless of format, so
+    /// callers get a single [`PredictionsResponse`] either way.
+    pub async fn fetch_predictions_csv(
+        &self,
+        request: &PredictionsRequest,
+    ) -> Result<PredictionsResponse, NoaaTideError> {
+        let bytes = self
+            .http
+            .get(&self.base_url)
+            .query(&request)
+            .query(&[
+                ("product", request.product_name()),
+                ("format", Format::Csv.query_value()),
+            ])
+            .send()
+            .await?
+            .bytes()
+            .await?;
+
+        // CO-OPS always returns errors as a JSON body, regardless of the requested format, so
+        // check for that shape before handing the bytes to the CSV reader.
+        if let Ok(ErrorWrapper { error }) = serde_json::from_slice::<ErrorWrapper>(&bytes) {
+            return Err(NoaaTideError::ApiError(error.message));
+        }
+
+        PredictionsResponse::from_csv(&bytes)
+    }
+
+    /// Find stations within `radius` kilometers of `point`, sorted nearest-first
+    pub async fn find_stations(
+        &self,
+        point: Point,
+        radius: f32,
+    ) -> Result<Vec<Station>, NoaaTideError> {
+        let mut stations = self.stations().await?;
+        stations.retain(|station| point.distance_to(&station.point()) <= radius);
+        stations.sort_by(|a, b| {
+            point
+                .distance_to(&a.point())
+                .total_cmp(&point.distance_to(&b.point()))
+        });
+        Ok(stations)
+    }
+
+    /// Find the single nearest station to `point`
+    pub async fn nearest_station(&self, point: Point) -> Result<Option<Station>, NoaaTideError> {
+        let stations = self.stations().await?;
+        Ok(stations.into_iter().min_by(|a, b| {
+            point
+                .distance_to(&a.point())
+                .total_cmp(&point.distance_to(&b.point()))
+        }))
+    }
+
+    async fn stations(&self) -> Result<Vec<Station>, NoaaTideError> {
+        let response = self
+            .http
+            .get(&self.mdapi_stations_url)
+            .send()
+            .await?
+            .json::<crate::metadata::StationsResponse>()
+            .await?;
+        Ok(response.stations)
+    }
+
+    /// Fetch predictions for a date range of any length, transparently splitting it into
+    /// sub-requests that respect the CO-OPS API's per-interval date range limits.
+    ///
+    /// Sub-requests are issued concurrently, bounded by a small semaphore, and the results are
+    /// merged back into a single chronologically-ordered [`PredictionsResponse`], de-duplicating
+    /// any datetime that happens to be returned by more than one chunk. Requests using a relative
+    /// [`TimeRange`] (anything other than [`TimeRange::Explicit`]) have no date range to split,
+    /// so they're forwarded to [`fetch`](Self::fetch) unchanged.
+    pub async fn fetch_predictions_all(
+        &self,
+        request: &PredictionsRequest,
+    ) -> Result<PredictionsResponse, NoaaTideError> {
+        let TimeRange::Explicit(date_range) = request.time_range else {
+            return self.fetch(request).await;
+        };
+
+        let chunks = split_date_range(date_range, max_span_days(request.interval));
+        let semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_CHUNK_REQUESTS));
+
+        let responses = stream::iter(chunks.into_iter().map(|date_range| {
+            let semaphore = Arc::clone(&semaphore);
+            let chunk_request = PredictionsRequest {
+                time_range: TimeRange::Explicit(date_range),
+                ..request.clone()
+            };
+            async move {
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .expect("semaphore is never closed");
+                self.fetch(&chunk_request).await
+            }
+        }))
+        .buffer_unordered(MAX_CONCURRENT_CHUNK_REQUESTS)
+        .collect::<Vec<_>>()
+        .await;
+
+        let mut seen_datetimes = HashSet::new();
+        let mut predictions = Vec::new();
+        for response in responses {
+            for prediction in response?.predictions {
+                if seen_datetimes.insert(prediction.datetime) {
+                    predictions.push(prediction);
+                }
+            }
+        }
+        predictions.sort_by_key(|prediction| prediction.datetime);
+
+        Ok(PredictionsResponse { predictions })
+    }
 }
 
 impl Default for NoaaTideClient {
@@ -132,6 +279,15 @@ pub enum NoaaTideError {
     #[error("NOAA API returned an error: {0}")]
     ApiError(String),
 
+    #[error("missing required field: {0}")]
+    MissingField(&'static str),
+
+    #[error("failed to parse CSV response: {0}")]
+    CsvError(String),
+
+    #[error("{0} is not a valid local time (falls in a DST spring-forward gap)")]
+    InvalidLocalDatetime(chrono::NaiveDateTime),
+
     #[error("Unknown error occurred")]
     Unknown,
 }
@@ -139,6 +295,7 @@ pub enum NoaaTideError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::NaiveDate;
     use mockito;
 
     #[derive(Debug, Serialize)]
@@ -177,6 +334,7 @@ mod tests {
         let client = NoaaTideClient {
             http: Client::new(),
             base_url: server.url(),
+            mdapi_stations_url: MDAPI_STATIONS_URL.to_string(),
         };
 
         let request = MockProductRequest {
@@ -188,4 +346,226 @@ mod tests {
         mock.assert_async().await;
         assert_eq!(result.unwrap().value, 10);
     }
+
+    #[tokio::test]
+    async fn fetch_predictions_csv_parses_successful_response() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/")
+            .match_query(mockito::Matcher::UrlEncoded("format".into(), "csv".into()))
+            .with_status(200)
+            .with_header("content-type", "text/csv")
+            .with_body("Date Time,Prediction,Type\n2026-01-01 03:12,3.456,H\n")
+            .create_async()
+            .await;
+
+        let client = NoaaTideClient {
+            http: Client::new(),
+            base_url: server.url(),
+            mdapi_stations_url: MDAPI_STATIONS_URL.to_string(),
+        };
+
+        let request = PredictionsRequest::builder()
+            .station("9414290")
+            .begin_date(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 1, 31).unwrap())
+            .build()
+            .unwrap();
+
+        let result = client.fetch_predictions_csv(&request).await.unwrap();
+        mock.assert_async().await;
+        assert_eq!(result.predictions.len(), 1);
+        assert_eq!(result.predictions[0].height, 3.456);
+    }
+
+    #[tokio::test]
+    async fn fetch_predictions_csv_surfaces_noaa_error_response() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/")
+            .match_query(mockito::Matcher::UrlEncoded("format".into(), "csv".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error": {"message": "No Predictions data was found."}}"#)
+            .create_async()
+            .await;
+
+        let client = NoaaTideClient {
+            http: Client::new(),
+            base_url: server.url(),
+            mdapi_stations_url: MDAPI_STATIONS_URL.to_string(),
+        };
+
+        let request = PredictionsRequest::builder()
+            .station("9414290")
+            .begin_date(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 1, 31).unwrap())
+            .build()
+            .unwrap();
+
+        let result = client.fetch_predictions_csv(&request).await;
+        mock.assert_async().await;
+        assert!(matches!(
+            result,
+            Err(NoaaTideError::ApiError(ref msg)) if msg == "No Predictions data was found."
+        ));
+    }
+
+    #[tokio::test]
+    async fn find_stations_filters_and_sorts_by_distance() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                    "stations": [
+                        {"id": "B", "name": "Far", "lat": 21.3069, "lng": -157.8583, "state": "HI"},
+                        {"id": "A", "name": "Near", "lat": 37.8063, "lng": -122.4659, "state": "CA"}
+                    ]
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let client = NoaaTideClient {
+            http: Client::new(),
+            base_url: BASE_URL.to_string(),
+            mdapi_stations_url: server.url(),
+        };
+
+        let point = Point {
+            lat: 37.8,
+            lng: -122.4,
+        };
+        let stations = client.find_stations(point, 50.0).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(stations.len(), 1);
+        assert_eq!(stations[0].id, "A");
+    }
+
+    #[tokio::test]
+    async fn nearest_station_returns_closest_station() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                    "stations": [
+                        {"id": "B", "name": "Far", "lat": 21.3069, "lng": -157.8583, "state": "HI"},
+                        {"id": "A", "name": "Near", "lat": 37.8063, "lng": -122.4659, "state": "CA"}
+                    ]
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let client = NoaaTideClient {
+            http: Client::new(),
+            base_url: BASE_URL.to_string(),
+            mdapi_stations_url: server.url(),
+        };
+
+        let point = Point {
+            lat: 37.8,
+            lng: -122.4,
+        };
+        let station = client.nearest_station(point).await.unwrap().unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(station.id, "A");
+    }
+
+    #[tokio::test]
+    async fn fetch_predictions_all_merges_chunked_sub_requests() {
+        let mut server = mockito::Server::new_async().await;
+
+        // A 33-day range at `Interval::SixMinutes` (31-day max span) splits into two chunks:
+        // Jan 1-31, then Feb 1-2. Mock each chunk's sub-request independently.
+        let first_chunk = server
+            .mock("GET", "/")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("begin_date".into(), "20260101".into()),
+                mockito::Matcher::UrlEncoded("end_date".into(), "20260131".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"predictions": [{"t": "2026-01-15 00:00", "v": "1.0", "type": "H"}]}"#,
+            )
+            .create_async()
+            .await;
+        let second_chunk = server
+            .mock("GET", "/")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("begin_date".into(), "20260201".into()),
+                mockito::Matcher::UrlEncoded("end_date".into(), "20260202".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"predictions": [{"t": "2026-02-01 00:00", "v": "2.0", "type": "L"}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = NoaaTideClient {
+            http: Client::new(),
+            base_url: server.url(),
+            mdapi_stations_url: MDAPI_STATIONS_URL.to_string(),
+        };
+
+        let request = PredictionsRequest::builder()
+            .station("9414290")
+            .begin_date(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 2, 2).unwrap())
+            .interval(Interval::SixMinutes)
+            .build()
+            .unwrap();
+
+        let result = client.fetch_predictions_all(&request).await.unwrap();
+
+        first_chunk.assert_async().await;
+        second_chunk.assert_async().await;
+        assert_eq!(result.predictions.len(), 2);
+        // merged chronologically, earliest chunk first
+        assert!(result.predictions[0].datetime < result.predictions[1].datetime);
+    }
+
+    #[tokio::test]
+    async fn fetch_predictions_all_surfaces_noaa_error_response() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error": {"message": "No data was found."}}"#)
+            .create_async()
+            .await;
+
+        let client = NoaaTideClient {
+            http: Client::new(),
+            base_url: server.url(),
+            mdapi_stations_url: MDAPI_STATIONS_URL.to_string(),
+        };
+
+        let request = PredictionsRequest::builder()
+            .station("9414290")
+            .begin_date(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 1, 31).unwrap())
+            .build()
+            .unwrap();
+
+        let result = client.fetch_predictions_all(&request).await;
+
+        mock.assert_async().await;
+        assert!(matches!(
+            result,
+            Err(NoaaTideError::ApiError(ref msg)) if msg == "No data was found."
+        ));
+    }
 }
@@ -0,0 +1,177 @@
+// Station metadata lookups against the CO-OPS metadata API (MDAPI), see documentation:
+// <https://api.tidesandcurrents.noaa.gov/mdapi/prod/>
+
+use chrono_tz::Tz;
+use serde::Deserialize;
+
+/// Mean radius of the earth, in kilometers, used for haversine distance calculations
+const EARTH_RADIUS_KM: f32 = 6371.0;
+
+/// A point on the earth's surface, given in decimal degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+    pub lat: f32,
+    pub lng: f32,
+}
+
+impl Point {
+    /// Great-circle distance to another point, in kilometers, using the haversine formula
+    pub fn distance_to(&self, other: &Point) -> f32 {
+        let delta_lat = (other.lat - self.lat).to_radians();
+        let delta_lng = (other.lng - self.lng).to_radians();
+        let lat1 = self.lat.to_radians();
+        let lat2 = other.lat.to_radians();
+
+        let a = (delta_lat / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * (delta_lng / 2.0).sin().powi(2);
+
+        EARTH_RADIUS_KM * 2.0 * a.sqrt().asin()
+    }
+}
+
+/// A CO-OPS station returned by the metadata API
+#[derive(Debug, Deserialize)]
+pub struct Station {
+    pub id: String,
+    pub name: String,
+    pub lat: f32,
+    pub lng: f32,
+    pub state: Option<String>,
+}
+
+impl Station {
+    /// The station's location as a [`Point`]
+    pub fn point(&self) -> Point {
+        Point {
+            lat: self.lat,
+            lng: self.lng,
+        }
+    }
+
+    /// Best-effort IANA timezone for this station, looked up from its `state`.
+    ///
+    /// The MDAPI station list does not return an IANA zone name directly, so this falls back to
+    /// one representative zone per U.S. state/territory. A station in a part of a state that
+    /// keeps a different zone still gets the state's representative zone.
+    pub fn timezone(&self) -> Option<Tz> {
+        state_timezone(self.state.as_deref()?)
+    }
+}
+
+fn state_timezone(state: &str) -> Option<Tz> {
+    use chrono_tz::America;
+
+    Some(match state {
+        "ME" | "NH" | "MA" | "RI" | "CT" | "NY" | "NJ" | "DE" | "MD" | "VA" | "NC" | "SC"
+        | "GA" | "FL" | "DC" | "MI" | "OH" => America::New_York,
+        "IN" | "IL" | "WI" | "MN" | "IA" | "MO" | "AL" | "MS" | "LA" | "AR" | "TN" | "KY" => {
+            America::Chicago
+        }
+        "TX" => America::Chicago,
+        "CO" | "MT" | "WY" | "UT" | "NM" => America::Denver,
+        "CA" | "OR" | "WA" | "NV" => America::Los_Angeles,
+        "AK" => America::Anchorage,
+        "HI" => chrono_tz::Pacific::Honolulu,
+        "PR" | "VI" => America::Puerto_Rico,
+        "GU" | "MP" => chrono_tz::Pacific::Guam,
+        "AS" => chrono_tz::Pacific::Pago_Pago,
+        _ => return None,
+    })
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct StationsResponse {
+    pub stations: Vec<Station>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distance_to_same_point_is_zero() {
+        let point = Point {
+            lat: 37.8063,
+            lng: -122.4659,
+        };
+        assert_eq!(point.distance_to(&point), 0.0);
+    }
+
+    #[test]
+    fn distance_to_matches_known_haversine_distance() {
+        // San Francisco Golden Gate station to Honolulu, roughly 3857 km apart
+        let sf = Point {
+            lat: 37.8063,
+            lng: -122.4659,
+        };
+        let honolulu = Point {
+            lat: 21.3069,
+            lng: -157.8583,
+        };
+
+        let distance = sf.distance_to(&honolulu);
+        assert!((distance - 3857.0).abs() < 15.0, "distance was {distance}");
+    }
+
+    #[test]
+    fn timezone_looks_up_known_state() {
+        let station = Station {
+            id: "9414290".to_string(),
+            name: "San Francisco".to_string(),
+            lat: 37.8063,
+            lng: -122.4659,
+            state: Some("CA".to_string()),
+        };
+        assert_eq!(station.timezone(), Some(chrono_tz::America::Los_Angeles));
+    }
+
+    #[test]
+    fn timezone_uses_eastern_for_ohio_and_michigan_lake_erie_stations() {
+        // Ohio and Michigan are entirely (or overwhelmingly) Eastern time; neither should be
+        // bucketed with the Central-time Great Lakes states.
+        for state in ["OH", "MI"] {
+            let station = Station {
+                id: "9063053".to_string(),
+                name: "Toledo".to_string(),
+                lat: 41.6934,
+                lng: -83.4723,
+                state: Some(state.to_string()),
+            };
+            assert_eq!(station.timezone(), Some(chrono_tz::America::New_York));
+        }
+    }
+
+    #[test]
+    fn timezone_is_none_without_a_state() {
+        let station = Station {
+            id: "9414290".to_string(),
+            name: "San Francisco".to_string(),
+            lat: 37.8063,
+            lng: -122.4659,
+            state: None,
+        };
+        assert_eq!(station.timezone(), None);
+    }
+
+    #[test]
+    fn stations_response_deserialization() {
+        let data = r#"
+        {
+            "stations": [{
+                "id": "9414290",
+                "name": "San Francisco",
+                "lat": 37.8063,
+                "lng": -122.4659,
+                "state": "CA"
+            }]
+        }
+        "#;
+        let response = serde_json::from_str::<StationsResponse>(data).unwrap();
+
+        assert_eq!(response.stations.len(), 1);
+        let station = &response.stations[0];
+        assert_eq!(station.id, "9414290");
+        assert_eq!(station.name, "San Francisco");
+        assert_eq!(station.state.as_deref(), Some("CA"));
+    }
+}
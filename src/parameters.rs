@@ -1,11 +1,11 @@
 // Parameters used in NOAA CO-OPS API requests
 
 use chrono::NaiveDate;
-use serde::Serialize;
+use serde::{Serialize, Serializer};
 
 /// Represents datum options for requests, see documentation:
 /// <https://api.tidesandcurrents.noaa.gov/api/prod/#datum>
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub enum Datum {
     /// Mean Higher High Water
     MHHW,
@@ -33,7 +33,7 @@ pub enum Datum {
 
 /// Represents timezone options for requests, see documentation:
 /// <https://api.tidesandcurrents.noaa.gov/api/prod/#timezone>
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Copy, Serialize)]
 #[serde(rename_all = "lowercase")]
 #[allow(non_camel_case_types)]
 pub enum Timezone {
@@ -47,7 +47,7 @@ pub enum Timezone {
 
 /// Represents interval options for requests, see documentation:
 /// <https://api.tidesandcurrents.noaa.gov/api/prod/#interval>
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub enum Interval {
     #[serde(rename = "h")]
     Hourly,
@@ -71,7 +71,7 @@ pub enum Interval {
 
 /// Represents units options for requests, see documentation:
 /// <https://api.tidesandcurrents.noaa.gov/api/prod/#units>
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Copy, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Units {
     /// Metric units (Celsius, meters, cm/s appropriate for the data)
@@ -82,9 +82,29 @@ pub enum Units {
     English,
 }
 
+/// Represents the response format for requests, see documentation:
+/// <https://api.tidesandcurrents.noaa.gov/api/prod/#output>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The default response format, decoded with `serde_json`
+    Json,
+    /// A more compact response format for large pulls, decoded with the `csv` crate
+    Csv,
+}
+
+impl Format {
+    /// The value of the `format` query parameter for this format
+    pub(crate) fn query_value(&self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            Format::Csv => "csv",
+        }
+    }
+}
+
 /// Represents date range parameters for requests, see documentation:
 /// <https://api.tidesandcurrents.noaa.gov/api/prod/#timerange>
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub struct DateRange {
     #[serde(serialize_with = "yyyymmdd::serialize")]
     pub begin_date: NaiveDate,
@@ -93,6 +113,68 @@ pub struct DateRange {
     pub end_date: NaiveDate,
 }
 
+/// Represents the time parameters for requests, see documentation:
+/// <https://api.tidesandcurrents.noaa.gov/api/prod/#timerange>
+///
+/// Most requests use [`TimeRange::Explicit`], but the CO-OPS API also accepts relative time
+/// windows so callers don't need to compute calendar dates for the common "today" or "next N
+/// hours" cases.
+#[derive(Debug, Clone, Copy)]
+pub enum TimeRange {
+    /// An explicit begin/end date range
+    Explicit(DateRange),
+    /// The current day's data
+    Today,
+    /// The most recent observation only
+    Latest,
+    /// The last 3 days of data
+    Recent,
+    /// `range` hours of data starting at `begin_date`
+    BeginWithRange { begin_date: NaiveDate, range: u32 },
+    /// `range` hours of data ending at `end_date`
+    EndWithRange { end_date: NaiveDate, range: u32 },
+}
+
+impl Serialize for TimeRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        match self {
+            TimeRange::Explicit(date_range) => date_range.serialize(serializer),
+            TimeRange::Today => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("date", "today")?;
+                map.end()
+            }
+            TimeRange::Latest => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("date", "latest")?;
+                map.end()
+            }
+            TimeRange::Recent => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("date", "recent")?;
+                map.end()
+            }
+            TimeRange::BeginWithRange { begin_date, range } => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("begin_date", &begin_date.format("%Y%m%d").to_string())?;
+                map.serialize_entry("range", range)?;
+                map.end()
+            }
+            TimeRange::EndWithRange { end_date, range } => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("end_date", &end_date.format("%Y%m%d").to_string())?;
+                map.serialize_entry("range", range)?;
+                map.end()
+            }
+        }
+    }
+}
+
 mod yyyymmdd {
     use chrono::NaiveDate;
     use serde::Serializer;
@@ -109,6 +191,7 @@ mod yyyymmdd {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_urlencoded;
 
     #[test]
     fn date_range_serialization() {
@@ -122,4 +205,57 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn time_range_explicit_serializes_like_date_range() {
+        let time_range = TimeRange::Explicit(DateRange {
+            begin_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+        });
+
+        let actual = serde_json::to_string(&time_range).unwrap();
+        let expected = r#"{"begin_date":"20260101","end_date":"20260131"}"#;
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn time_range_today_serializes_to_date_query_param() {
+        let actual = serde_urlencoded::to_string(TimeRange::Today).unwrap();
+        assert_eq!(actual, "date=today");
+    }
+
+    #[test]
+    fn time_range_latest_serializes_to_date_query_param() {
+        let actual = serde_urlencoded::to_string(TimeRange::Latest).unwrap();
+        assert_eq!(actual, "date=latest");
+    }
+
+    #[test]
+    fn time_range_recent_serializes_to_date_query_param() {
+        let actual = serde_urlencoded::to_string(TimeRange::Recent).unwrap();
+        assert_eq!(actual, "date=recent");
+    }
+
+    #[test]
+    fn time_range_begin_with_range_serializes_both_params() {
+        let time_range = TimeRange::BeginWithRange {
+            begin_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            range: 48,
+        };
+
+        let actual = serde_urlencoded::to_string(time_range).unwrap();
+        assert_eq!(actual, "begin_date=20260101&range=48");
+    }
+
+    #[test]
+    fn time_range_end_with_range_serializes_both_params() {
+        let time_range = TimeRange::EndWithRange {
+            end_date: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+            range: 48,
+        };
+
+        let actual = serde_urlencoded::to_string(time_range).unwrap();
+        assert_eq!(actual, "end_date=20260131&range=48");
+    }
 }
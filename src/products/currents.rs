@@ -0,0 +1,98 @@
+use super::{de_string_to_f32, de_string_to_native_datetime, NoaaTideProduct};
+use crate::parameters::{TimeRange, Timezone, Units};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// Request parameters for observed currents
+#[derive(Debug, Serialize)]
+pub struct CurrentsRequest {
+    pub station: String,
+    pub time_zone: Timezone,
+    pub units: Units,
+
+    /// Currents bin number, only required for stations with multiple current bins
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bin: Option<u32>,
+
+    #[serde(flatten)]
+    pub time_range: TimeRange,
+}
+
+impl NoaaTideProduct for CurrentsRequest {
+    type Response = CurrentsResponse;
+
+    fn product_name(&self) -> &'static str {
+        "currents"
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CurrentsResponse {
+    pub data: Vec<Current>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Current {
+    #[serde(rename = "t", deserialize_with = "de_string_to_native_datetime")]
+    pub datetime: NaiveDateTime,
+
+    #[serde(rename = "s", deserialize_with = "de_string_to_f32")]
+    pub speed: f32,
+
+    /// Direction of the current, in degrees true
+    #[serde(rename = "d", deserialize_with = "de_string_to_f32")]
+    pub direction: f32,
+
+    /// Current bin number the reading was taken from
+    #[serde(rename = "b")]
+    pub bin: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parameters::DateRange;
+    use chrono::NaiveDate;
+    use serde_urlencoded;
+
+    #[test]
+    fn request_query() {
+        let request = CurrentsRequest {
+            station: "1234567".to_string(),
+            time_range: TimeRange::Explicit(DateRange {
+                begin_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+            }),
+            time_zone: Timezone::LST_LDT,
+            units: Units::English,
+            bin: Some(1),
+        };
+
+        let query = serde_urlencoded::to_string(&request).unwrap();
+
+        let expected = "station=1234567&time_zone=lst_ldt&units=english&bin=1&\
+            begin_date=20260101&end_date=20260131";
+        assert_eq!(query, expected);
+    }
+
+    #[test]
+    fn response_deserialization_success() {
+        let data = r#"
+        {
+            "data": [{
+                "t": "2026-01-01 12:34",
+                "s": "0.290",
+                "d": "297.6",
+                "b": "16"
+            }]
+        }
+        "#;
+        let response = serde_json::from_str::<CurrentsResponse>(data).unwrap();
+
+        assert_eq!(response.data.len(), 1);
+        let reading = &response.data[0];
+        assert_eq!(reading.speed, 0.290);
+        assert_eq!(reading.direction, 297.6);
+        assert_eq!(reading.bin, "16");
+    }
+}
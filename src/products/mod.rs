@@ -1,8 +1,23 @@
+pub mod air_temperature;
+pub mod currents;
 pub mod predictions;
+pub mod water_level;
+pub mod water_temperature;
+pub mod wind;
 
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Deserializer};
 
+/// Implemented by every CO-OPS product request so [`crate::NoaaTideClient::fetch`] can be
+/// generic over which product is being requested.
+pub trait NoaaTideProduct {
+    /// Response type returned by the CO-OPS API for this product
+    type Response;
+
+    /// The CO-OPS `product` query parameter value for this request, e.g. `"predictions"`
+    fn product_name(&self) -> &'static str;
+}
+
 fn de_string_to_f32<'de, D>(deserializer: D) -> Result<f32, D::Error>
 where
     D: Deserializer<'de>,
@@ -1,10 +1,12 @@
-use super::{de_string_to_f32, de_string_to_native_datetime};
-use crate::params::{DateRange, Datum, Interval, Timezone, Units};
-use chrono::NaiveDateTime;
+use super::{de_string_to_f32, de_string_to_native_datetime, NoaaTideProduct};
+use crate::parameters::{DateRange, Datum, Interval, TimeRange, Timezone, Units};
+use crate::NoaaTideError;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
 /// Request parameters for tide predictions
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PredictionsRequest {
     pub station: String,
     pub datum: Datum,
@@ -13,7 +15,115 @@ pub struct PredictionsRequest {
     pub units: Units,
 
     #[serde(flatten)]
-    pub date_range: DateRange,
+    pub time_range: TimeRange,
+}
+
+impl NoaaTideProduct for PredictionsRequest {
+    type Response = PredictionsResponse;
+
+    fn product_name(&self) -> &'static str {
+        "predictions"
+    }
+}
+
+impl PredictionsRequest {
+    /// Start building a [`PredictionsRequest`], filling in sensible defaults
+    /// (MLLW datum, LST/LDT timezone, English units) for any field left unset
+    pub fn builder() -> PredictionsRequestBuilder {
+        PredictionsRequestBuilder::default()
+    }
+}
+
+/// Builder for [`PredictionsRequest`], see [`PredictionsRequest::builder`]
+#[derive(Debug, Default)]
+pub struct PredictionsRequestBuilder {
+    station: Option<String>,
+    datum: Option<Datum>,
+    time_zone: Option<Timezone>,
+    interval: Option<Interval>,
+    units: Option<Units>,
+    begin_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+    time_range: Option<TimeRange>,
+}
+
+impl PredictionsRequestBuilder {
+    pub fn station(mut self, station: impl Into<String>) -> Self {
+        self.station = Some(station.into());
+        self
+    }
+
+    pub fn datum(mut self, datum: Datum) -> Self {
+        self.datum = Some(datum);
+        self
+    }
+
+    pub fn time_zone(mut self, time_zone: Timezone) -> Self {
+        self.time_zone = Some(time_zone);
+        self
+    }
+
+    pub fn interval(mut self, interval: Interval) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    pub fn units(mut self, units: Units) -> Self {
+        self.units = Some(units);
+        self
+    }
+
+    pub fn begin_date(mut self, begin_date: NaiveDate) -> Self {
+        self.begin_date = Some(begin_date);
+        self
+    }
+
+    pub fn end_date(mut self, end_date: NaiveDate) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Use a relative or otherwise non-explicit [`TimeRange`] instead of `begin_date`/`end_date`,
+    /// e.g. `TimeRange::Today` or `TimeRange::Recent`. Takes precedence over `begin_date`/`end_date`
+    /// if both are set.
+    pub fn time_range(mut self, time_range: TimeRange) -> Self {
+        self.time_range = Some(time_range);
+        self
+    }
+
+    /// Build the [`PredictionsRequest`], applying defaults for any unset optional field.
+    ///
+    /// # Errors
+    /// Returns [`NoaaTideError::MissingField`] if `station` was never set, or if neither
+    /// `time_range` nor both of `begin_date`/`end_date` were set, since the API has no sensible
+    /// default for them.
+    pub fn build(self) -> Result<PredictionsRequest, NoaaTideError> {
+        let station = self.station.ok_or(NoaaTideError::MissingField("station"))?;
+        let time_range = match self.time_range {
+            Some(time_range) => time_range,
+            None => {
+                let begin_date = self
+                    .begin_date
+                    .ok_or(NoaaTideError::MissingField("begin_date"))?;
+                let end_date = self
+                    .end_date
+                    .ok_or(NoaaTideError::MissingField("end_date"))?;
+                TimeRange::Explicit(DateRange {
+                    begin_date,
+                    end_date,
+                })
+            }
+        };
+
+        Ok(PredictionsRequest {
+            station,
+            datum: self.datum.unwrap_or(Datum::MLLW),
+            time_zone: self.time_zone.unwrap_or(Timezone::LST_LDT),
+            interval: self.interval.unwrap_or(Interval::HighLow),
+            units: self.units.unwrap_or(Units::English),
+            time_range,
+        })
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -21,6 +131,35 @@ pub struct PredictionsResponse {
     pub predictions: Vec<Prediction>,
 }
 
+impl PredictionsResponse {
+    /// Attach timezone information to every prediction, see [`Prediction::localize`]
+    pub fn localize(
+        &self,
+        time_zone: Timezone,
+        tz: Tz,
+    ) -> Result<Vec<ZonedPrediction>, NoaaTideError> {
+        self.predictions
+            .iter()
+            .map(|prediction| prediction.localize(time_zone, tz))
+            .collect()
+    }
+
+    /// Parse a `predictions` response body returned in CSV format, see
+    /// [`NoaaTideClient::fetch_predictions_csv`](crate::NoaaTideClient::fetch_predictions_csv)
+    pub(crate) fn from_csv(bytes: &[u8]) -> Result<Self, NoaaTideError> {
+        let mut reader = csv::ReaderBuilder::new().from_reader(bytes);
+        let predictions = reader
+            .deserialize::<PredictionRow>()
+            .map(|row| {
+                let row = row.map_err(|e| NoaaTideError::CsvError(e.to_string()))?;
+                Prediction::try_from(row)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PredictionsResponse { predictions })
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Prediction {
     #[serde(rename = "t", deserialize_with = "de_string_to_native_datetime")]
@@ -33,6 +172,70 @@ pub struct Prediction {
     pub tide_type: Option<TideType>,
 }
 
+impl Prediction {
+    /// Attach timezone information to this prediction's naive `datetime`.
+    ///
+    /// `time_zone` should be the [`Timezone`] the originating [`PredictionsRequest`] was made
+    /// with. For [`Timezone::GMT`] the naive datetime is interpreted as UTC and converted to
+    /// `tz`. For [`Timezone::LST`] the naive datetime is always standard time local to the
+    /// station, never DST-corrected, so it's resolved using `tz`'s fixed standard offset rather
+    /// than its date-sensitive DST rules. For [`Timezone::LST_LDT`] the naive datetime is treated
+    /// as wall-clock local time in `tz`, with its real DST transitions applied; ambiguous DST
+    /// fall-back folds are resolved by preferring the standard-time offset over daylight time.
+    ///
+    /// # Errors
+    /// Returns [`NoaaTideError::InvalidLocalDatetime`] if `self.datetime` falls in a DST spring
+    /// forward gap, where it never occurred as a local wall-clock time in `tz`.
+    pub fn localize(&self, time_zone: Timezone, tz: Tz) -> Result<ZonedPrediction, NoaaTideError> {
+        let datetime = match time_zone {
+            Timezone::GMT => Utc
+                .from_utc_datetime(&self.datetime)
+                .with_timezone(&tz),
+            Timezone::LST => {
+                // NOAA's `lst` datetimes never observe DST, so resolve `tz`'s offset from a
+                // known-standard-time instant instead of asking `from_local_datetime` to apply
+                // the zone's real (date-sensitive) DST rules.
+                let standard_offset = tz
+                    .offset_from_utc_datetime(
+                        &NaiveDate::from_ymd_opt(self.datetime.year(), 1, 1)
+                            .unwrap()
+                            .and_hms_opt(0, 0, 0)
+                            .unwrap(),
+                    )
+                    .fix();
+                let utc_datetime =
+                    self.datetime - Duration::seconds(standard_offset.local_minus_utc() as i64);
+                Utc.from_utc_datetime(&utc_datetime).with_timezone(&tz)
+            }
+            Timezone::LST_LDT => tz
+                .from_local_datetime(&self.datetime)
+                .latest()
+                .ok_or(NoaaTideError::InvalidLocalDatetime(self.datetime))?,
+        };
+
+        Ok(ZonedPrediction {
+            datetime,
+            height: self.height,
+            tide_type: self.tide_type,
+        })
+    }
+}
+
+/// A [`Prediction`] with its datetime resolved to a specific timezone, see [`Prediction::localize`]
+#[derive(Debug, Clone)]
+pub struct ZonedPrediction {
+    pub datetime: DateTime<Tz>,
+    pub height: f32,
+    pub tide_type: Option<TideType>,
+}
+
+impl ZonedPrediction {
+    /// Unix timestamp, in seconds, of this prediction
+    pub fn ts_seconds(&self) -> i64 {
+        self.datetime.timestamp()
+    }
+}
+
 /// Variants of all possible tide types in prediction responses
 #[derive(Debug, Deserialize, PartialEq, Clone, Copy)]
 pub enum TideType {
@@ -46,6 +249,87 @@ pub enum TideType {
     LowerLow,
 }
 
+/// A single row of a `predictions` CSV response, see [`PredictionsResponse::from_csv`]
+#[derive(Debug, Deserialize)]
+struct PredictionRow {
+    #[serde(rename = "Date Time")]
+    date_time: String,
+    #[serde(rename = "Prediction")]
+    prediction: String,
+    #[serde(rename = "Type")]
+    tide_type: Option<String>,
+}
+
+impl TryFrom<PredictionRow> for Prediction {
+    type Error = NoaaTideError;
+
+    fn try_from(row: PredictionRow) -> Result<Self, Self::Error> {
+        let datetime = NaiveDateTime::parse_from_str(&row.date_time, "%Y-%m-%d %H:%M")
+            .map_err(|e| NoaaTideError::CsvError(format!("invalid \"Date Time\" column: {e}")))?;
+
+        let height = row
+            .prediction
+            .trim()
+            .parse::<f32>()
+            .map_err(|e| NoaaTideError::CsvError(format!("invalid \"Prediction\" column: {e}")))?;
+
+        let tide_type = row
+            .tide_type
+            .map(|raw| match raw.trim() {
+                "H" => Ok(TideType::High),
+                "L" => Ok(TideType::Low),
+                "HH" => Ok(TideType::HigherHigh),
+                "LL" => Ok(TideType::LowerLow),
+                other => Err(NoaaTideError::CsvError(format!(
+                    "invalid \"Type\" column: unknown tide type {other:?}"
+                ))),
+            })
+            .transpose()?;
+
+        Ok(Prediction {
+            datetime,
+            height,
+            tide_type,
+        })
+    }
+}
+
+/// The longest span of days the CO-OPS API accepts for a single `predictions` request at the
+/// given interval, see <https://api.tidesandcurrents.noaa.gov/api/prod/#timerange>
+pub(crate) fn max_span_days(interval: Interval) -> i64 {
+    match interval {
+        Interval::OneMinute
+        | Interval::FiveMinutes
+        | Interval::SixMinutes
+        | Interval::TenMinutes
+        | Interval::FifteenMinutes
+        | Interval::ThirtyMinutes
+        | Interval::SixtyMinutes => 31,
+        Interval::Hourly => 365,
+        Interval::HighLow => 365 * 10,
+    }
+}
+
+/// Split `date_range` into contiguous sub-ranges no longer than `max_days` each
+pub(crate) fn split_date_range(date_range: DateRange, max_days: i64) -> Vec<DateRange> {
+    let mut ranges = Vec::new();
+    let mut begin_date = date_range.begin_date;
+
+    while begin_date <= date_range.end_date {
+        let end_date = std::cmp::min(
+            begin_date + chrono::Duration::days(max_days - 1),
+            date_range.end_date,
+        );
+        ranges.push(DateRange {
+            begin_date,
+            end_date,
+        });
+        begin_date = end_date + chrono::Duration::days(1);
+    }
+
+    ranges
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,10 +340,10 @@ mod tests {
     fn request_query() {
         let request = PredictionsRequest {
             station: "1234567".to_string(),
-            date_range: DateRange {
+            time_range: TimeRange::Explicit(DateRange {
                 begin_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
                 end_date: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
-            },
+            }),
             datum: Datum::MLLW,
             time_zone: Timezone::LST_LDT,
             interval: Interval::HighLow,
@@ -100,4 +384,247 @@ mod tests {
         assert_eq!(prediction.height, expected_height);
         assert_eq!(prediction.tide_type, expected_tide_type);
     }
+
+    #[test]
+    fn builder_applies_defaults() {
+        let request = PredictionsRequest::builder()
+            .station("9414290")
+            .begin_date(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 1, 31).unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(request.station, "9414290");
+        assert!(matches!(request.datum, Datum::MLLW));
+        assert!(matches!(request.time_zone, Timezone::LST_LDT));
+        assert!(matches!(request.interval, Interval::HighLow));
+        assert!(matches!(request.units, Units::English));
+    }
+
+    #[test]
+    fn builder_overrides_defaults() {
+        let request = PredictionsRequest::builder()
+            .station("9414290")
+            .begin_date(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 1, 31).unwrap())
+            .interval(Interval::Hourly)
+            .units(Units::Metric)
+            .build()
+            .unwrap();
+
+        assert!(matches!(request.interval, Interval::Hourly));
+        assert!(matches!(request.units, Units::Metric));
+    }
+
+    #[test]
+    fn builder_requires_station_and_date_range() {
+        let result = PredictionsRequest::builder().build();
+        assert!(matches!(result, Err(NoaaTideError::MissingField("station"))));
+
+        let result = PredictionsRequest::builder().station("9414290").build();
+        assert!(matches!(result, Err(NoaaTideError::MissingField("begin_date"))));
+    }
+
+    #[test]
+    fn builder_time_range_overrides_begin_and_end_date() {
+        let request = PredictionsRequest::builder()
+            .station("9414290")
+            .begin_date(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 1, 31).unwrap())
+            .time_range(TimeRange::Today)
+            .build()
+            .unwrap();
+
+        assert!(matches!(request.time_range, TimeRange::Today));
+    }
+
+    #[test]
+    fn localize_gmt_interprets_naive_datetime_as_utc() {
+        let prediction = Prediction {
+            datetime: NaiveDateTime::new(
+                NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+                chrono::NaiveTime::from_hms_opt(12, 34, 0).unwrap(),
+            ),
+            height: 3.5,
+            tide_type: Some(TideType::High),
+        };
+
+        let zoned = prediction
+            .localize(Timezone::GMT, chrono_tz::America::Los_Angeles)
+            .unwrap();
+
+        assert_eq!(zoned.datetime.naive_utc(), prediction.datetime);
+        assert_eq!(zoned.height, prediction.height);
+    }
+
+    #[test]
+    fn localize_local_timezone_uses_station_tz_offset() {
+        let prediction = Prediction {
+            datetime: NaiveDateTime::new(
+                NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+                chrono::NaiveTime::from_hms_opt(12, 34, 0).unwrap(),
+            ),
+            height: 3.5,
+            tide_type: Some(TideType::High),
+        };
+
+        let zoned = prediction
+            .localize(Timezone::LST_LDT, chrono_tz::America::Los_Angeles)
+            .unwrap();
+
+        // PST in January is UTC-8
+        assert_eq!(zoned.datetime.naive_local(), prediction.datetime);
+        assert_eq!(
+            zoned.datetime.naive_utc(),
+            prediction.datetime + Duration::hours(8)
+        );
+        assert_eq!(zoned.ts_seconds(), zoned.datetime.timestamp());
+    }
+
+    #[test]
+    fn localize_dst_gap_returns_error_instead_of_panicking() {
+        let prediction = Prediction {
+            // 2026-03-08 02:00-03:00 America/Los_Angeles never occurs: clocks spring forward
+            // from 02:00 PST directly to 03:00 PDT.
+            datetime: NaiveDateTime::new(
+                NaiveDate::from_ymd_opt(2026, 3, 8).unwrap(),
+                chrono::NaiveTime::from_hms_opt(2, 30, 0).unwrap(),
+            ),
+            height: 3.5,
+            tide_type: Some(TideType::High),
+        };
+
+        let result = prediction.localize(Timezone::LST_LDT, chrono_tz::America::Los_Angeles);
+
+        assert!(matches!(
+            result,
+            Err(NoaaTideError::InvalidLocalDatetime(dt)) if dt == prediction.datetime
+        ));
+    }
+
+    #[test]
+    fn localize_lst_stays_on_standard_offset_during_dst() {
+        let prediction = Prediction {
+            // A summer date: America/Los_Angeles observes PDT (UTC-7) in July, but `lst` never
+            // applies DST, so this should still resolve using the standard PST (UTC-8) offset.
+            datetime: NaiveDateTime::new(
+                NaiveDate::from_ymd_opt(2026, 7, 1).unwrap(),
+                chrono::NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            ),
+            height: 3.5,
+            tide_type: Some(TideType::High),
+        };
+
+        let zoned = prediction
+            .localize(Timezone::LST, chrono_tz::America::Los_Angeles)
+            .unwrap();
+
+        // PST is UTC-8 year-round under `lst`, so 12:00 local is 20:00 UTC, not 19:00 UTC (PDT).
+        assert_eq!(
+            zoned.datetime.naive_utc(),
+            NaiveDateTime::new(
+                NaiveDate::from_ymd_opt(2026, 7, 1).unwrap(),
+                chrono::NaiveTime::from_hms_opt(20, 0, 0).unwrap(),
+            )
+        );
+    }
+
+    #[test]
+    fn localize_dst_fold_prefers_standard_time() {
+        let prediction = Prediction {
+            // 2026-11-01 01:30 America/Los_Angeles is ambiguous: it occurs once at 01:30 PDT,
+            // then again an hour later at 01:30 PST when clocks fall back.
+            datetime: NaiveDateTime::new(
+                NaiveDate::from_ymd_opt(2026, 11, 1).unwrap(),
+                chrono::NaiveTime::from_hms_opt(1, 30, 0).unwrap(),
+            ),
+            height: 3.5,
+            tide_type: Some(TideType::High),
+        };
+
+        let zoned = prediction
+            .localize(Timezone::LST_LDT, chrono_tz::America::Los_Angeles)
+            .unwrap();
+
+        // PST (UTC-8) is the second, standard-time occurrence; PDT (UTC-7) would be 1 hour earlier.
+        assert_eq!(
+            zoned.datetime.naive_utc(),
+            NaiveDateTime::new(
+                NaiveDate::from_ymd_opt(2026, 11, 1).unwrap(),
+                chrono::NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+            )
+        );
+    }
+
+    #[test]
+    fn split_date_range_within_limit_is_unsplit() {
+        let date_range = DateRange {
+            begin_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+        };
+
+        let ranges = split_date_range(date_range, 31);
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0].begin_date, date_range.begin_date);
+        assert_eq!(ranges[0].end_date, date_range.end_date);
+    }
+
+    #[test]
+    fn split_date_range_splits_into_contiguous_chunks() {
+        let date_range = DateRange {
+            begin_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+        };
+
+        let ranges = split_date_range(date_range, 31);
+
+        // 61 days split into 31-day chunks: Jan 1-31, then Feb 1-Mar 2
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(ranges[0].begin_date, NaiveDate::from_ymd_opt(2026, 1, 1).unwrap());
+        assert_eq!(ranges[0].end_date, NaiveDate::from_ymd_opt(2026, 1, 31).unwrap());
+        assert_eq!(ranges[1].begin_date, NaiveDate::from_ymd_opt(2026, 2, 1).unwrap());
+        assert_eq!(ranges[1].end_date, date_range.end_date);
+
+        // every chunk is contiguous, with no gaps or overlaps
+        for pair in ranges.windows(2) {
+            assert_eq!(pair[1].begin_date, pair[0].end_date + chrono::Duration::days(1));
+        }
+    }
+
+    #[test]
+    fn max_span_days_matches_co_ops_limits() {
+        assert_eq!(max_span_days(Interval::SixMinutes), 31);
+        assert_eq!(max_span_days(Interval::Hourly), 365);
+        assert_eq!(max_span_days(Interval::HighLow), 3650);
+    }
+
+    #[test]
+    fn from_csv_parses_rows_into_predictions() {
+        let csv = "Date Time,Prediction,Type\n\
+            2026-01-01 03:12,3.456,H\n\
+            2026-01-01 09:45,0.123,L\n";
+
+        let response = PredictionsResponse::from_csv(csv.as_bytes()).unwrap();
+
+        assert_eq!(response.predictions.len(), 2);
+        assert_eq!(
+            response.predictions[0].datetime,
+            NaiveDateTime::new(
+                NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+                chrono::NaiveTime::from_hms_opt(3, 12, 0).unwrap(),
+            )
+        );
+        assert_eq!(response.predictions[0].height, 3.456);
+        assert_eq!(response.predictions[0].tide_type, Some(TideType::High));
+        assert_eq!(response.predictions[1].tide_type, Some(TideType::Low));
+    }
+
+    #[test]
+    fn from_csv_surfaces_unknown_tide_type_as_error() {
+        let csv = "Date Time,Prediction,Type\n2026-01-01 03:12,3.456,X\n";
+
+        let result = PredictionsResponse::from_csv(csv.as_bytes());
+
+        assert!(matches!(result, Err(NoaaTideError::CsvError(_))));
+    }
 }
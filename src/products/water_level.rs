@@ -0,0 +1,108 @@
+use super::{de_string_to_f32, de_string_to_native_datetime, NoaaTideProduct};
+use crate::parameters::{Datum, TimeRange, Timezone, Units};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// Request parameters for observed water levels
+#[derive(Debug, Serialize)]
+pub struct WaterLevelRequest {
+    pub station: String,
+    pub datum: Datum,
+    pub time_zone: Timezone,
+    pub units: Units,
+
+    #[serde(flatten)]
+    pub time_range: TimeRange,
+}
+
+impl NoaaTideProduct for WaterLevelRequest {
+    type Response = WaterLevelResponse;
+
+    fn product_name(&self) -> &'static str {
+        "water_level"
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WaterLevelResponse {
+    pub data: Vec<WaterLevel>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WaterLevel {
+    #[serde(rename = "t", deserialize_with = "de_string_to_native_datetime")]
+    pub datetime: NaiveDateTime,
+
+    #[serde(rename = "v", deserialize_with = "de_string_to_f32")]
+    pub height: f32,
+
+    #[serde(rename = "s", deserialize_with = "de_string_to_f32")]
+    pub sigma: f32,
+
+    /// Data flags: max slope, rate of change, and flat tolerance checks
+    #[serde(rename = "f")]
+    pub flags: String,
+
+    #[serde(rename = "q")]
+    pub quality: WaterLevelQuality,
+}
+
+/// Whether an observed water level is preliminary or has been verified
+#[derive(Debug, Deserialize, PartialEq, Clone, Copy)]
+pub enum WaterLevelQuality {
+    #[serde(rename = "p")]
+    Preliminary,
+    #[serde(rename = "v")]
+    Verified,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parameters::DateRange;
+    use chrono::NaiveDate;
+    use serde_urlencoded;
+
+    #[test]
+    fn request_query() {
+        let request = WaterLevelRequest {
+            station: "1234567".to_string(),
+            time_range: TimeRange::Explicit(DateRange {
+                begin_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+            }),
+            datum: Datum::MLLW,
+            time_zone: Timezone::LST_LDT,
+            units: Units::English,
+        };
+
+        let query = serde_urlencoded::to_string(&request).unwrap();
+
+        let expected = "station=1234567&datum=MLLW&time_zone=lst_ldt&units=english&\
+            begin_date=20260101&end_date=20260131";
+        assert_eq!(query, expected);
+    }
+
+    #[test]
+    fn response_deserialization_success() {
+        let data = r#"
+        {
+            "data": [{
+                "t": "2026-01-01 12:34",
+                "v": "3.5",
+                "s": "0.017",
+                "f": "0,0,0,0",
+                "q": "v"
+            }]
+        }
+        "#;
+        let response = serde_json::from_str::<WaterLevelResponse>(data).unwrap();
+
+        assert_eq!(response.data.len(), 1);
+        let reading = &response.data[0];
+        assert_eq!(reading.height, 3.5);
+        assert_eq!(reading.sigma, 0.017);
+        assert_eq!(reading.flags, "0,0,0,0");
+        assert_eq!(reading.quality, WaterLevelQuality::Verified);
+    }
+}
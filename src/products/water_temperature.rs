@@ -0,0 +1,87 @@
+use super::{de_string_to_f32, de_string_to_native_datetime, NoaaTideProduct};
+use crate::parameters::{TimeRange, Timezone, Units};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// Request parameters for water temperature observations
+#[derive(Debug, Serialize)]
+pub struct WaterTemperatureRequest {
+    pub station: String,
+    pub time_zone: Timezone,
+    pub units: Units,
+
+    #[serde(flatten)]
+    pub time_range: TimeRange,
+}
+
+impl NoaaTideProduct for WaterTemperatureRequest {
+    type Response = WaterTemperatureResponse;
+
+    fn product_name(&self) -> &'static str {
+        "water_temperature"
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WaterTemperatureResponse {
+    pub data: Vec<WaterTemperature>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WaterTemperature {
+    #[serde(rename = "t", deserialize_with = "de_string_to_native_datetime")]
+    pub datetime: NaiveDateTime,
+
+    #[serde(rename = "v", deserialize_with = "de_string_to_f32")]
+    pub temperature: f32,
+
+    /// Data flags: max slope, rate of change, and flat tolerance checks
+    #[serde(rename = "f")]
+    pub flags: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parameters::DateRange;
+    use chrono::NaiveDate;
+    use serde_urlencoded;
+
+    #[test]
+    fn request_query() {
+        let request = WaterTemperatureRequest {
+            station: "1234567".to_string(),
+            time_range: TimeRange::Explicit(DateRange {
+                begin_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+                end_date: NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+            }),
+            time_zone: Timezone::LST_LDT,
+            units: Units::English,
+        };
+
+        let query = serde_urlencoded::to_string(&request).unwrap();
+
+        let expected = "station=1234567&time_zone=lst_ldt&units=english&\
+            begin_date=20260101&end_date=20260131";
+        assert_eq!(query, expected);
+    }
+
+    #[test]
+    fn response_deserialization_success() {
+        let data = r#"
+        {
+            "data": [{
+                "t": "2026-01-01 12:34",
+                "v": "58.3",
+                "f": "0,0,0,0"
+            }]
+        }
+        "#;
+        let response = serde_json::from_str::<WaterTemperatureResponse>(data).unwrap();
+
+        assert_eq!(response.data.len(), 1);
+        let reading = &response.data[0];
+        assert_eq!(reading.temperature, 58.3);
+        assert_eq!(reading.flags, "0,0,0,0");
+    }
+}